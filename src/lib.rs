@@ -20,8 +20,78 @@
 
 #![no_std]
 
+use core::iter::FusedIterator;
 use smallvec::SmallVec;
 
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// Distributes `pulses` onsets across `length` steps using the canonical recursive
+/// Bjorklund algorithm: start with `pulses` sequences `[true]` and `length - pulses`
+/// sequences `[false]`, then repeatedly distribute the smaller group onto the larger until
+/// at most one remainder sequence is left, and flatten the heads followed by the remainders.
+fn bjorklund(length: usize, pulses: usize) -> SmallVec<[bool; 64]> {
+    if length == 0 || pulses == 0 {
+        let mut steps = SmallVec::new();
+        for _ in 0..length {
+            steps.push(false);
+        }
+        return steps;
+    }
+
+    let mut heads: SmallVec<[SmallVec<[bool; 8]>; 64]> = SmallVec::new();
+    for _ in 0..pulses {
+        let mut seq = SmallVec::new();
+        seq.push(true);
+        heads.push(seq);
+    }
+
+    let mut remainders: SmallVec<[SmallVec<[bool; 8]>; 64]> = SmallVec::new();
+    for _ in 0..(length - pulses) {
+        let mut seq = SmallVec::new();
+        seq.push(false);
+        remainders.push(seq);
+    }
+
+    while remainders.len() > 1 {
+        let split = core::cmp::min(heads.len(), remainders.len());
+        let mut next_heads: SmallVec<[SmallVec<[bool; 8]>; 64]> = SmallVec::new();
+        for i in 0..split {
+            let mut seq = heads[i].clone();
+            seq.extend(remainders[i].iter().copied());
+            next_heads.push(seq);
+        }
+
+        let next_remainders = if heads.len() > split {
+            heads[split..].iter().cloned().collect()
+        } else {
+            remainders[split..].iter().cloned().collect()
+        };
+
+        heads = next_heads;
+        remainders = next_remainders;
+    }
+
+    let mut steps = SmallVec::new();
+    for seq in heads.iter().chain(remainders.iter()) {
+        steps.extend(seq.iter().copied());
+    }
+    steps
+}
+
 /// The main pattern building block
 #[derive(Debug, Clone)]
 pub struct Pattern {
@@ -151,6 +221,157 @@ impl Pattern {
         }
     }
 
+    /// Updates the current pattern with a evenly distributed number of pulses, using the
+    /// canonical recursive Bjorklund algorithm.
+    ///
+    /// Unlike [`Pattern::pulses`], which approximates the distribution with a bucket
+    /// accumulator, this always matches the textbook Bjorklund output, including cases where
+    /// `gcd(length, pulses) > 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pulses` - Total number of pulses, from `0` to the pattern length, clamped to the
+    ///   pattern length if it exceeds it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let mut pattern = Pattern::with_length(8);
+    /// pattern.pulses_bjorklund(3);
+    /// assert_eq!([true, false, false, true, false, false, true, false], pattern.as_slice());
+    /// ```
+    pub fn pulses_bjorklund(&mut self, pulses: usize) {
+        self.pulses = if pulses > self.length {
+            self.length
+        } else {
+            pulses
+        };
+
+        self.steps = bjorklund(self.length, self.pulses);
+    }
+
+    /// Returns the indices of the pattern's onsets (steps that are `true`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let mut pattern = Pattern::with_length(8);
+    /// pattern.pulses_bjorklund(3);
+    /// assert_eq!([0, 3, 6], pattern.onsets().as_slice());
+    /// ```
+    pub fn onsets(&self) -> SmallVec<[usize; 64]> {
+        self.steps
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &step)| if step { Some(i) } else { None })
+            .collect()
+    }
+
+    /// Returns the inter-onset gaps of the pattern, cyclically, the way Euclidean rhythms are
+    /// usually named and compared (e.g. the tresillo is `[3, 3, 2]`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let mut pattern = Pattern::with_length(8);
+    /// pattern.pulses_bjorklund(3);
+    /// assert_eq!([3, 3, 2], pattern.intervals().as_slice());
+    /// ```
+    pub fn intervals(&self) -> SmallVec<[usize; 64]> {
+        let onsets = self.onsets();
+        let mut intervals = SmallVec::new();
+        if onsets.is_empty() {
+            return intervals;
+        }
+        for window in onsets.windows(2) {
+            intervals.push(window[1] - window[0]);
+        }
+        intervals.push(self.length + onsets[0] - onsets[onsets.len() - 1]);
+        intervals
+    }
+
+    /// Returns whether some cyclic rotation of `self` equals `other`
+    ///
+    /// World rhythms are usually classified up to rotation: a rhythm and all its rotations
+    /// form one "necklace". Patterns must have equal length and onset count to be considered,
+    /// as a fast reject before the rotation search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let a = Pattern::new(4, 2, 0);
+    /// let b = Pattern::new(4, 2, 1);
+    /// assert!(a.is_rotation_of(&b));
+    /// ```
+    pub fn is_rotation_of(&self, other: &Pattern) -> bool {
+        if self.length != other.length {
+            return false;
+        }
+        if self.onsets().len() != other.onsets().len() {
+            return false;
+        }
+        if self.length == 0 {
+            return true;
+        }
+
+        let doubled: SmallVec<[bool; 128]> = self
+            .steps
+            .iter()
+            .chain(self.steps.iter())
+            .copied()
+            .collect();
+        doubled
+            .windows(self.length)
+            .any(|window| window == other.steps.as_slice())
+    }
+
+    /// Returns the lexicographically smallest rotation of the pattern, a canonical
+    /// representative of its rotation necklace
+    ///
+    /// Useful for deduplicating generated patterns, e.g. detecting that the tresillo and all
+    /// of its rotations are the same underlying rhythm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let pattern = Pattern::new(4, 2, 0);
+    /// assert_eq!([false, true, false, true], pattern.canonical().as_slice());
+    /// ```
+    pub fn canonical(&self) -> Pattern {
+        if self.length == 0 {
+            return self.clone();
+        }
+
+        let doubled: SmallVec<[bool; 128]> = self
+            .steps
+            .iter()
+            .chain(self.steps.iter())
+            .copied()
+            .collect();
+
+        let mut best = 0;
+        for start in 1..self.length {
+            if doubled[start..start + self.length] < doubled[best..best + self.length] {
+                best = start;
+            }
+        }
+
+        let steps: SmallVec<[bool; 64]> = doubled[best..best + self.length].iter().copied().collect();
+        let pulses = steps.iter().filter(|&&step| step).count();
+        Pattern {
+            steps,
+            length: self.length,
+            pulses,
+            rotation: 0,
+            cursor: 0,
+        }
+    }
+
     /// Rotates the current pattern
     ///
     /// # Arguments
@@ -218,6 +439,116 @@ impl Pattern {
         self.length = length;
     }
 
+    /// Returns a new pattern holding the logical OR of `self` and `other`'s onsets
+    ///
+    /// If the patterns have different lengths, each is repeated up to the least common
+    /// multiple of the two lengths before combining, so a 3-step pattern against a 4-step
+    /// pattern produces a 12-step composite, the way layered polyrhythms align.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let a = Pattern::new(4, 1, 0);
+    /// let b = Pattern::new(4, 1, 1);
+    /// assert_eq!([false, false, true, true], a.union(&b).as_slice());
+    /// ```
+    pub fn union(&self, other: &Pattern) -> Pattern {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns a new pattern holding the logical AND of `self` and `other`'s onsets
+    ///
+    /// See [`Pattern::union`] for how patterns of unequal length are aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let a = Pattern::new(4, 2, 0);
+    /// let b = Pattern::new(4, 2, 1);
+    /// assert_eq!([false, false, false, false], a.intersection(&b).as_slice());
+    /// ```
+    pub fn intersection(&self, other: &Pattern) -> Pattern {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Returns a new pattern holding the logical XOR of `self` and `other`'s onsets
+    ///
+    /// See [`Pattern::union`] for how patterns of unequal length are aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let a = Pattern::new(4, 1, 0);
+    /// let b = Pattern::new(4, 1, 1);
+    /// assert_eq!([false, false, true, true], a.symmetric_difference(&b).as_slice());
+    /// ```
+    pub fn symmetric_difference(&self, other: &Pattern) -> Pattern {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    /// Returns a new pattern holding the onsets present in `self` but not in `other`
+    ///
+    /// See [`Pattern::union`] for how patterns of unequal length are aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let a = Pattern::new(4, 2, 0);
+    /// let b = Pattern::new(4, 1, 0);
+    /// assert_eq!([true, false, false, false], a.difference(&b).as_slice());
+    /// ```
+    pub fn difference(&self, other: &Pattern) -> Pattern {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Returns a new pattern with `other`'s steps appended after `self`'s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let a = Pattern::new(2, 1, 0);
+    /// let b = Pattern::new(2, 1, 1);
+    /// assert_eq!([true, false, false, true], a.concat(&b).as_slice());
+    /// ```
+    pub fn concat(&self, other: &Pattern) -> Pattern {
+        let mut steps = self.steps.clone();
+        steps.extend(other.steps.iter().copied());
+        let length = steps.len();
+        let pulses = steps.iter().filter(|&&step| step).count();
+        Pattern {
+            steps,
+            length,
+            pulses,
+            rotation: 0,
+            cursor: 0,
+        }
+    }
+
+    /// Combines `self` and `other` step-by-step with `op`, repeating each pattern up to the
+    /// least common multiple of the two lengths
+    fn combine(&self, other: &Pattern, op: impl Fn(bool, bool) -> bool) -> Pattern {
+        let length = lcm(self.len(), other.len());
+        let mut steps = SmallVec::with_capacity(length);
+        for i in 0..length {
+            let a = self.steps[i % self.len()];
+            let b = other.steps[i % other.len()];
+            steps.push(op(a, b));
+        }
+        let pulses = steps.iter().filter(|&&step| step).count();
+        Pattern {
+            steps,
+            length,
+            pulses,
+            rotation: 0,
+            cursor: 0,
+        }
+    }
+
     /// Moves the pattern cursor to the first step
     ///
     /// # Examples
@@ -308,6 +639,28 @@ impl Pattern {
         self.steps.as_slice()
     }
 
+    /// Returns a borrowing iterator over the pattern's steps
+    ///
+    /// Unlike the consuming `Iterator` implementation on `Pattern`, this does not move the
+    /// pattern or disturb its playback cursor, so the pattern can still be iterated again or
+    /// advanced with [`Pattern::next_looped`] afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rhythms::Pattern;
+    /// let pattern = Pattern::new(4, 2, 0);
+    /// assert_eq!(2, pattern.iter().filter(|&step| step).count());
+    /// assert_eq!([true, false, true, false], pattern.as_slice());
+    /// ```
+    pub fn iter(&self) -> PatternIter<'_> {
+        PatternIter {
+            steps: self.as_slice(),
+            front: 0,
+            back: self.len(),
+        }
+    }
+
     /// Returns the next step in a pattern. If the end of the pattern is reached, it resets
     /// the cursor and will return the first step
     ///
@@ -364,6 +717,56 @@ impl Iterator for Pattern {
     }
 }
 
+/// A borrowing iterator over a pattern's steps, created by [`Pattern::iter`]
+///
+/// Supports double-ended traversal (for retrograde playback), reports an exact
+/// `size_hint`/`len`, and is fused, so it composes with the standard adapter suite
+/// (`step_by`, `cycle`, `enumerate`, `zip`, ...) without reimplementing traversal.
+#[derive(Debug, Clone)]
+pub struct PatternIter<'a> {
+    steps: &'a [bool],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for PatternIter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.front < self.back {
+            let step = self.steps[self.front];
+            self.front += 1;
+            Some(step)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for PatternIter<'a> {
+    fn next_back(&mut self) -> Option<bool> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.steps[self.back])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for PatternIter<'a> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<'a> FusedIterator for PatternIter<'a> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -467,4 +870,126 @@ mod tests {
             pattern.as_slice()
         );
     }
+
+    #[test]
+    fn pulses_bjorklund_matches_gcd_case() {
+        let mut pattern = Pattern::with_length(16);
+        pattern.pulses_bjorklund(4);
+        assert_eq!(
+            [
+                true, false, false, false, true, false, false, false, true, false, false, false,
+                true, false, false, false
+            ],
+            pattern.as_slice()
+        );
+    }
+
+    #[test]
+    fn pulses_bjorklund_tresillo() {
+        let mut pattern = Pattern::with_length(8);
+        pattern.pulses_bjorklund(3);
+        assert_eq!(
+            [true, false, false, true, false, false, true, false],
+            pattern.as_slice()
+        );
+    }
+
+    #[test]
+    fn onsets_and_intervals_of_tresillo() {
+        let mut pattern = Pattern::with_length(8);
+        pattern.pulses_bjorklund(3);
+        assert_eq!([0, 3, 6], pattern.onsets().as_slice());
+        assert_eq!([3, 3, 2], pattern.intervals().as_slice());
+    }
+
+    #[test]
+    fn is_rotation_of_accepts_rotations() {
+        let a = Pattern::new(4, 2, 0);
+        let b = Pattern::new(4, 2, 1);
+        assert!(a.is_rotation_of(&b));
+    }
+
+    #[test]
+    fn is_rotation_of_rejects_different_onset_count() {
+        let a = Pattern::new(4, 2, 0);
+        let b = Pattern::new(4, 1, 0);
+        assert!(!a.is_rotation_of(&b));
+    }
+
+    #[test]
+    fn canonical_picks_lexicographically_smallest_rotation() {
+        let pattern = Pattern::new(4, 2, 0);
+        let canonical = pattern.canonical();
+        assert_eq!([false, true, false, true], canonical.as_slice());
+        assert!(pattern.is_rotation_of(&canonical));
+    }
+
+    #[test]
+    fn canonical_is_shared_across_a_necklace() {
+        let a = Pattern::new(4, 2, 0);
+        let b = Pattern::new(4, 2, 1);
+        assert_eq!(a.canonical().as_slice(), b.canonical().as_slice());
+    }
+
+    #[test]
+    fn iter_does_not_consume_pattern() {
+        let pattern = Pattern::new(4, 2, 0);
+        let collected: SmallVec<[bool; 64]> = pattern.iter().collect();
+        assert_eq!(pattern.as_slice(), collected.as_slice());
+        // the pattern is still usable after iterating, unlike the consuming `Iterator` impl
+        assert_eq!(4, pattern.iter().count());
+    }
+
+    #[test]
+    fn union_aligns_to_lcm_of_lengths() {
+        let a = Pattern::new(3, 1, 0);
+        let b = Pattern::new(4, 1, 0);
+        let combined = a.union(&b);
+        assert_eq!(12, combined.len());
+    }
+
+    #[test]
+    fn intersection_of_disjoint_patterns_is_empty() {
+        let a = Pattern::new(4, 2, 0);
+        let b = Pattern::new(4, 2, 1);
+        assert_eq!(
+            [false, false, false, false],
+            a.intersection(&b).as_slice()
+        );
+    }
+
+    #[test]
+    fn difference_removes_shared_onsets() {
+        let a = Pattern::new(4, 2, 0);
+        let b = Pattern::new(4, 1, 0);
+        assert_eq!(
+            [true, false, false, false],
+            a.difference(&b).as_slice()
+        );
+    }
+
+    #[test]
+    fn concat_appends_steps() {
+        let a = Pattern::new(2, 1, 0);
+        let b = Pattern::new(2, 1, 1);
+        assert_eq!(
+            [true, false, false, true],
+            a.concat(&b).as_slice()
+        );
+    }
+
+    #[test]
+    fn iter_is_double_ended_and_exact_size() {
+        let pattern = Pattern::new(4, 2, 0);
+        let mut iter = pattern.iter();
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(true), iter.next());
+        assert_eq!(Some(false), iter.next_back());
+        assert_eq!(2, iter.len());
+        assert_eq!(
+            [false, true],
+            [iter.next().unwrap(), iter.next().unwrap()]
+        );
+        assert_eq!(None, iter.next());
+    }
 }
\ No newline at end of file